@@ -0,0 +1,111 @@
+use wasm_bindgen::prelude::*;
+
+use crate::ReaderLimits;
+
+/// Trims and flattens a batch of lines into display-ready tokens in a single
+/// WASM boundary crossing, instead of one call per word.
+///
+/// Each line is split on whitespace, with empty lines contributing nothing,
+/// so callers can hand over a whole paragraph already split into
+/// sentences/words and get back the flattened token list. Enforces
+/// `limits.maxInputBytes` on the input and `limits.maxTokenCount` on the
+/// resulting token count.
+#[wasm_bindgen(js_name = prepareTokens)]
+pub fn prepare_tokens(lines: Vec<String>, limits: &ReaderLimits) -> Result<Vec<String>, JsError> {
+    flatten(lines, limits).map_err(|e| JsError::new(&e))
+}
+
+/// `None`-preserving variant of [`prepare_tokens`] for optional batches.
+#[wasm_bindgen(js_name = prepareTokensOpt)]
+pub fn prepare_tokens_opt(
+    lines: Option<Vec<String>>,
+    limits: &ReaderLimits,
+) -> Result<Option<Vec<String>>, JsError> {
+    lines
+        .map(|lines| flatten(lines, limits))
+        .transpose()
+        .map_err(|e| JsError::new(&e))
+}
+
+/// Same validation as [`prepare_tokens`] but with a plain error, so the
+/// limit-enforcement branches are exercisable from native unit tests without
+/// going through `JsError`, which requires a JS host to construct.
+fn flatten(lines: Vec<String>, limits: &ReaderLimits) -> Result<Vec<String>, String> {
+    let total_bytes: usize = lines.iter().map(String::len).sum();
+    if total_bytes > limits.max_input_bytes() {
+        return Err(format!(
+            "input of {} bytes exceeds the configured limit of {} bytes",
+            total_bytes,
+            limits.max_input_bytes()
+        ));
+    }
+
+    let tokens: Vec<String> = lines
+        .iter()
+        .flat_map(|line| line.split_whitespace())
+        .map(str::to_owned)
+        .collect();
+
+    if tokens.len() > limits.max_token_count() {
+        return Err(format!(
+            "input contains {} tokens, exceeding the configured limit of {}",
+            tokens.len(),
+            limits.max_token_count()
+        ));
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn trims_and_flattens_lines() {
+        let limits = ReaderLimits::new(1_000, 1_000);
+        let tokens = flatten(lines(&["  hello   world  ", "foo"]), &limits).unwrap();
+        assert_eq!(tokens, vec!["hello", "world", "foo"]);
+    }
+
+    #[test]
+    fn empty_lines_contribute_nothing() {
+        let limits = ReaderLimits::new(1_000, 1_000);
+        let tokens = flatten(lines(&["", "   ", "hi"]), &limits).unwrap();
+        assert_eq!(tokens, vec!["hi"]);
+    }
+
+    #[test]
+    fn prepare_tokens_opt_passes_through_none() {
+        let limits = ReaderLimits::new(1_000, 1_000);
+        assert_eq!(
+            prepare_tokens_opt(None, &limits).unwrap(),
+            None::<Vec<String>>
+        );
+    }
+
+    #[test]
+    fn prepare_tokens_opt_processes_some() {
+        let limits = ReaderLimits::new(1_000, 1_000);
+        assert_eq!(
+            prepare_tokens_opt(Some(lines(&["a b"])), &limits).unwrap(),
+            Some(vec!["a".to_string(), "b".to_string()])
+        );
+    }
+
+    #[test]
+    fn rejects_oversized_input() {
+        let limits = ReaderLimits::new(4, 1_000);
+        assert!(flatten(lines(&["this is too long"]), &limits).is_err());
+    }
+
+    #[test]
+    fn rejects_too_many_tokens() {
+        let limits = ReaderLimits::new(1_000, 2);
+        assert!(flatten(lines(&["one two three"]), &limits).is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+use wasm_bindgen::prelude::*;
+
+use crate::ReaderLimits;
+
+/// Magic bytes that mark a gzip member (RFC 1952 section 2.3.1).
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Streams a document handed in from JS into reader tokens.
+///
+/// Accepts raw bytes (optionally gzip-compressed) from a browser `File`/`Blob`
+/// and exposes them as a cursor over whitespace-separated words, so the UI
+/// can pull words in batches without holding the whole decoded string on the
+/// JS side.
+#[wasm_bindgen]
+pub struct DocumentSource {
+    tokens: Vec<String>,
+    cursor: usize,
+}
+
+#[wasm_bindgen]
+impl DocumentSource {
+    /// Builds a `DocumentSource` from raw bytes, transparently inflating them
+    /// first if they look like a gzip stream.
+    ///
+    /// `limits.maxInputBytes` bounds the *decompressed* size too: inflation
+    /// stops as soon as the cap is crossed, so a small gzip bomb can't
+    /// exhaust memory before the check fires. `limits.maxTokenCount` is
+    /// enforced on the resulting token count.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(data: &[u8], limits: &ReaderLimits) -> Result<DocumentSource, JsError> {
+        Self::decode(data, limits).map_err(|e| JsError::new(&e))
+    }
+
+    fn decode(data: &[u8], limits: &ReaderLimits) -> Result<DocumentSource, String> {
+        if data.len() > limits.max_input_bytes() {
+            return Err(format!(
+                "input of {} bytes exceeds the configured limit of {} bytes",
+                data.len(),
+                limits.max_input_bytes()
+            ));
+        }
+
+        let decoded = if is_gzip(data) {
+            let cap = limits.max_input_bytes() as u64;
+            let mut inflated = Vec::new();
+            GzDecoder::new(data)
+                .take(cap + 1)
+                .read_to_end(&mut inflated)
+                .map_err(|e| format!("failed to inflate gzip input: {e}"))?;
+            if inflated.len() as u64 > cap {
+                return Err(format!(
+                    "decompressed input exceeds the configured limit of {} bytes",
+                    limits.max_input_bytes()
+                ));
+            }
+            inflated
+        } else {
+            data.to_vec()
+        };
+
+        let text = String::from_utf8(decoded)
+            .map_err(|e| format!("input is not valid UTF-8: {e}"))?;
+
+        let tokens: Vec<String> = text.split_whitespace().map(str::to_owned).collect();
+        if tokens.len() > limits.max_token_count() {
+            return Err(format!(
+                "input contains {} tokens, exceeding the configured limit of {}",
+                tokens.len(),
+                limits.max_token_count()
+            ));
+        }
+
+        Ok(DocumentSource { tokens, cursor: 0 })
+    }
+
+    /// Returns the next `n` tokens and advances the cursor, or `None` once
+    /// the document is exhausted.
+    #[wasm_bindgen(js_name = nextChunk)]
+    pub fn next_chunk(&mut self, n: usize) -> Option<Vec<String>> {
+        if self.cursor >= self.tokens.len() {
+            return None;
+        }
+        let end = (self.cursor + n).min(self.tokens.len());
+        let chunk = self.tokens[self.cursor..end].to_vec();
+        self.cursor = end;
+        Some(chunk)
+    }
+}
+
+fn is_gzip(data: &[u8]) -> bool {
+    data.starts_with(&GZIP_MAGIC)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+
+    use super::*;
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn is_gzip_detects_magic_bytes() {
+        assert!(is_gzip(&[0x1f, 0x8b, 0x08, 0x00]));
+        assert!(!is_gzip(b"plain text"));
+        assert!(!is_gzip(&[0x1f]));
+        assert!(!is_gzip(&[]));
+    }
+
+    #[test]
+    fn from_bytes_reads_plain_text() {
+        let limits = ReaderLimits::new(1_000, 1_000);
+        let mut source = DocumentSource::decode(b"hello world foo", &limits).unwrap();
+        assert_eq!(
+            source.next_chunk(2),
+            Some(vec!["hello".to_string(), "world".to_string()])
+        );
+        assert_eq!(source.next_chunk(2), Some(vec!["foo".to_string()]));
+        assert_eq!(source.next_chunk(2), None);
+    }
+
+    #[test]
+    fn from_bytes_inflates_gzip() {
+        let compressed = gzip(b"hello gzip world");
+        let limits = ReaderLimits::new(1_000, 1_000);
+        let mut source = DocumentSource::decode(&compressed, &limits).unwrap();
+        assert_eq!(
+            source.next_chunk(3),
+            Some(vec!["hello".to_string(), "gzip".to_string(), "world".to_string()])
+        );
+    }
+
+    #[test]
+    fn from_bytes_rejects_truncated_gzip() {
+        let mut compressed = gzip(b"hello gzip world");
+        compressed.truncate(compressed.len() - 4);
+        let limits = ReaderLimits::new(1_000, 1_000);
+        assert!(DocumentSource::decode(&compressed, &limits).is_err());
+    }
+
+    #[test]
+    fn from_bytes_rejects_invalid_utf8_after_inflate() {
+        let compressed = gzip(&[0xff, 0xfe, 0xfd]);
+        let limits = ReaderLimits::new(1_000, 1_000);
+        assert!(DocumentSource::decode(&compressed, &limits).is_err());
+    }
+
+    #[test]
+    fn from_bytes_rejects_oversized_input() {
+        let limits = ReaderLimits::new(4, 1_000);
+        assert!(DocumentSource::decode(b"this is too long", &limits).is_err());
+    }
+
+    #[test]
+    fn from_bytes_rejects_too_many_tokens() {
+        let limits = ReaderLimits::new(1_000, 2);
+        assert!(DocumentSource::decode(b"one two three", &limits).is_err());
+    }
+}
@@ -1,20 +1,37 @@
 use wasm_bindgen::prelude::*;
-use web_sys::console;
+
+mod batch;
+mod ingestion;
+mod limits;
+mod memory;
+mod settings;
+
+pub use batch::{prepare_tokens, prepare_tokens_opt};
+pub use ingestion::DocumentSource;
+pub use limits::ReaderLimits;
+pub use memory::{alloc, dealloc, LoadedText};
+pub use settings::{configure, ReaderSettings};
 
 #[wasm_bindgen]
-pub fn add(a: i32, b: i32) -> i32 {
-    // Check for potential overflow
-    a.checked_add(b).unwrap_or_else(|| {
-        console::error_1(&"Integer overflow occurred".into());
-        0
-    })
+pub fn add(a: i32, b: i32) -> Result<i32, JsError> {
+    a.checked_add(b)
+        .ok_or_else(|| JsError::new(&format!("arithmetic overflow: {a} + {b}")))
 }
 
+/// Greets the name held in a JS-written [`LoadedText`] buffer.
+///
+/// `text`'s length is checked against `limits.maxInputBytes` before the
+/// shared buffer is ever read, so an oversized input is rejected without the
+/// full name being copied or validated as UTF-8 first.
 #[wasm_bindgen]
-pub fn greet(name: &str) -> String {
-    // Limit input length for safety
-    if name.len() > 1000 {
-        return String::from("Error: Input too long");
+pub fn greet(text: &LoadedText, limits: &ReaderLimits) -> Result<String, JsError> {
+    if text.len() > limits.max_input_bytes() {
+        return Err(JsError::new(&format!(
+            "input of {} bytes exceeds the configured limit of {} bytes",
+            text.len(),
+            limits.max_input_bytes()
+        )));
     }
-    format!("Hello, {}!", name)
+
+    Ok(format!("Hello, {}!", text.as_str()?))
 }
\ No newline at end of file
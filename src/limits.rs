@@ -0,0 +1,86 @@
+use wasm_bindgen::prelude::*;
+
+/// Caller-configurable ceilings for anything that reads user-supplied text.
+///
+/// Replaces the old hardcoded 1000-byte cap on [`crate::greet`] with bounds
+/// the JS side can tune per document, and gives [`DocumentSource`] and the
+/// batch token APIs a shared place to enforce limits instead of each
+/// guessing its own.
+///
+/// [`DocumentSource`]: crate::DocumentSource
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub struct ReaderLimits {
+    max_input_bytes: usize,
+    max_token_count: usize,
+}
+
+#[wasm_bindgen]
+impl ReaderLimits {
+    #[wasm_bindgen(constructor)]
+    pub fn new(max_input_bytes: usize, max_token_count: usize) -> ReaderLimits {
+        ReaderLimits {
+            max_input_bytes,
+            max_token_count,
+        }
+    }
+
+    #[wasm_bindgen(getter = maxInputBytes)]
+    pub fn max_input_bytes(&self) -> usize {
+        self.max_input_bytes
+    }
+
+    #[wasm_bindgen(setter = maxInputBytes)]
+    pub fn set_max_input_bytes(&mut self, value: usize) {
+        self.max_input_bytes = value;
+    }
+
+    #[wasm_bindgen(getter = maxTokenCount)]
+    pub fn max_token_count(&self) -> usize {
+        self.max_token_count
+    }
+
+    #[wasm_bindgen(setter = maxTokenCount)]
+    pub fn set_max_token_count(&mut self, value: usize) {
+        self.max_token_count = value;
+    }
+}
+
+impl Default for ReaderLimits {
+    fn default() -> Self {
+        // Generous enough for a novel-length paste while still bounding
+        // worst-case allocation for a hostile input.
+        ReaderLimits {
+            max_input_bytes: 50_000_000,
+            max_token_count: 2_000_000,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_values() {
+        let limits = ReaderLimits::default();
+        assert_eq!(limits.max_input_bytes(), 50_000_000);
+        assert_eq!(limits.max_token_count(), 2_000_000);
+    }
+
+    #[test]
+    fn constructor_sets_fields() {
+        let limits = ReaderLimits::new(10, 20);
+        assert_eq!(limits.max_input_bytes(), 10);
+        assert_eq!(limits.max_token_count(), 20);
+    }
+
+    #[test]
+    fn setters_round_trip() {
+        let mut limits = ReaderLimits::new(10, 20);
+        limits.set_max_input_bytes(30);
+        limits.set_max_token_count(40);
+        assert_eq!(limits.max_input_bytes(), 30);
+        assert_eq!(limits.max_token_count(), 40);
+    }
+}
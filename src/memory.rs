@@ -0,0 +1,125 @@
+use std::mem;
+use std::slice;
+
+use wasm_bindgen::prelude::*;
+
+/// Allocates `len` bytes in WASM linear memory and leaks them for JS to fill.
+///
+/// JS writes into the returned pointer via
+/// `new Uint8Array(memory.buffer, ptr, len)` (typically paired with
+/// `TextEncoder.encodeInto`), then hands `ptr`/`len` to [`LoadedText::new`].
+/// The allocation must later be released with exactly the same `len` via
+/// [`dealloc`] — mismatched lengths reconstitute the wrong `Vec` capacity and
+/// risk an out-of-bounds access on drop.
+#[wasm_bindgen]
+pub fn alloc(len: usize) -> *mut u8 {
+    let mut buf = Vec::<u8>::with_capacity(len);
+    let ptr = buf.as_mut_ptr();
+    mem::forget(buf);
+    ptr
+}
+
+/// Releases memory previously returned by [`alloc`].
+///
+/// # Safety invariant
+/// `ptr` must be a pointer returned by `alloc`, `len` must be the exact
+/// value passed to that `alloc` call, and JS must not read or write through
+/// `ptr` after calling `dealloc` — the backing `Vec` is dropped immediately.
+#[wasm_bindgen]
+#[allow(clippy::not_unsafe_ptr_arg_deref)] // wasm_bindgen exports can't be `unsafe fn`; see safety invariant above
+pub fn dealloc(ptr: *mut u8, len: usize) {
+    unsafe {
+        drop(Vec::from_raw_parts(ptr, len, len));
+    }
+}
+
+/// A zero-copy view over a document written into linear memory by JS.
+///
+/// Reads tokens directly out of the shared buffer described by `ptr`/`len`
+/// without a second copy across the WASM boundary. JS must not touch the
+/// buffer (read, write, or `dealloc` it) while a `LoadedText` built over it
+/// is still in use.
+#[wasm_bindgen]
+pub struct LoadedText {
+    ptr: *const u8,
+    len: usize,
+}
+
+#[wasm_bindgen]
+impl LoadedText {
+    #[wasm_bindgen(constructor)]
+    pub fn new(ptr: *const u8, len: usize) -> LoadedText {
+        LoadedText { ptr, len }
+    }
+
+    /// Parses the shared buffer into whitespace-separated reader tokens.
+    ///
+    /// # Safety invariant
+    /// The caller must ensure `ptr`/`len` still describe a live allocation
+    /// (i.e. `dealloc` has not been called) for the duration of this call.
+    #[wasm_bindgen(js_name = tokenize)]
+    pub fn tokenize(&self) -> Result<Vec<String>, JsError> {
+        Ok(self.as_str()?.split_whitespace().map(str::to_owned).collect())
+    }
+}
+
+impl LoadedText {
+    /// The length, in bytes, of the shared buffer — safe to call without
+    /// touching the buffer itself, so callers can bound-check before reading.
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Reads the shared buffer as UTF-8 without copying it.
+    ///
+    /// # Safety invariant
+    /// Same as [`LoadedText::tokenize`].
+    pub(crate) fn as_str(&self) -> Result<&str, JsError> {
+        self.as_str_checked().map_err(|e| JsError::new(&e))
+    }
+
+    /// Same as [`LoadedText::as_str`] but with a plain error, so the
+    /// UTF-8 validation path is exercisable from native unit tests without
+    /// going through `JsError`, which requires a JS host to construct.
+    fn as_str_checked(&self) -> Result<&str, String> {
+        let bytes = unsafe { slice::from_raw_parts(self.ptr, self.len) };
+        std::str::from_utf8(bytes).map_err(|e| format!("shared buffer is not valid UTF-8: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alloc_dealloc_tokenize_round_trip() {
+        let data = b"hello shared world";
+        let ptr = alloc(data.len());
+        unsafe {
+            ptr.copy_from_nonoverlapping(data.as_ptr(), data.len());
+        }
+
+        let text = LoadedText::new(ptr, data.len());
+        assert_eq!(text.len(), data.len());
+        assert_eq!(
+            text.tokenize().unwrap(),
+            vec!["hello".to_string(), "shared".to_string(), "world".to_string()]
+        );
+
+        dealloc(ptr, data.len());
+    }
+
+    #[test]
+    fn as_str_rejects_invalid_utf8() {
+        let data = [0xff, 0xfe, 0xfd];
+        let ptr = alloc(data.len());
+        unsafe {
+            ptr.copy_from_nonoverlapping(data.as_ptr(), data.len());
+        }
+
+        let text = LoadedText::new(ptr, data.len());
+        assert!(text.as_str_checked().is_err());
+
+        dealloc(ptr, data.len());
+    }
+}
@@ -0,0 +1,176 @@
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+/// Validated reader preferences, parsed once from a JSON blob instead of a
+/// growing list of scalar setter functions.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub struct ReaderSettings {
+    words_per_minute: i32,
+    chunk_size: usize,
+    strip_punctuation: bool,
+    gzip_auto_detect: bool,
+}
+
+#[wasm_bindgen]
+impl ReaderSettings {
+    #[wasm_bindgen(getter = wordsPerMinute)]
+    pub fn words_per_minute(&self) -> i32 {
+        self.words_per_minute
+    }
+
+    #[wasm_bindgen(getter = chunkSize)]
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    #[wasm_bindgen(getter = stripPunctuation)]
+    pub fn strip_punctuation(&self) -> bool {
+        self.strip_punctuation
+    }
+
+    #[wasm_bindgen(getter = gzipAutoDetect)]
+    pub fn gzip_auto_detect(&self) -> bool {
+        self.gzip_auto_detect
+    }
+}
+
+const MIN_WPM: i32 = 50;
+const MAX_WPM: i32 = 1500;
+
+/// `usize` is 32 bits on the `wasm32` target this crate ships to, regardless
+/// of the native host this builds/tests on, so `chunkSize` is bounded by
+/// `u32::MAX` rather than the host's `usize::MAX` to avoid a silent
+/// wasm32-only truncation.
+const MAX_CHUNK_SIZE: u64 = u32::MAX as u64;
+
+/// Parses and validates a JSON settings blob into a [`ReaderSettings`].
+///
+/// Every field is optional-but-typed: a missing or `null` key takes its
+/// default, a wrongly-typed key is reported by name in the returned
+/// `JsError` rather than panicking, and `wordsPerMinute` is range-checked
+/// against a sane bound before being accepted.
+#[wasm_bindgen]
+pub fn configure(json: &str) -> Result<ReaderSettings, JsError> {
+    parse(json).map_err(|e| JsError::new(&e))
+}
+
+/// Same validation as [`configure`] but with a plain error, so the parsing
+/// and validation logic is exercisable from native unit tests without going
+/// through `JsError`, which requires a JS host to construct.
+fn parse(json: &str) -> Result<ReaderSettings, String> {
+    let value: Value =
+        serde_json::from_str(json).map_err(|e| format!("invalid settings JSON: {e}"))?;
+
+    let words_per_minute = field_i32(&value, "wordsPerMinute", 300)?;
+    if !(MIN_WPM..=MAX_WPM).contains(&words_per_minute) {
+        return Err(format!(
+            "wordsPerMinute must be between {MIN_WPM} and {MAX_WPM}, got {words_per_minute}"
+        ));
+    }
+
+    let chunk_size = field_u64(&value, "chunkSize", 1)?;
+    if chunk_size == 0 || chunk_size > MAX_CHUNK_SIZE {
+        return Err(format!(
+            "chunkSize must be between 1 and {MAX_CHUNK_SIZE}, got {chunk_size}"
+        ));
+    }
+
+    Ok(ReaderSettings {
+        words_per_minute,
+        chunk_size: chunk_size as usize,
+        strip_punctuation: field_bool(&value, "stripPunctuation", false)?,
+        gzip_auto_detect: field_bool(&value, "gzipAutoDetect", true)?,
+    })
+}
+
+fn field_i32(value: &Value, key: &str, default: i32) -> Result<i32, String> {
+    match value.get(key) {
+        None | Some(Value::Null) => Ok(default),
+        Some(v) => v
+            .as_i64()
+            .and_then(|n| i32::try_from(n).ok())
+            .ok_or_else(|| format!("\"{key}\" must be an integer, got {v}")),
+    }
+}
+
+fn field_u64(value: &Value, key: &str, default: u64) -> Result<u64, String> {
+    match value.get(key) {
+        None | Some(Value::Null) => Ok(default),
+        Some(v) => v
+            .as_u64()
+            .ok_or_else(|| format!("\"{key}\" must be a non-negative integer, got {v}")),
+    }
+}
+
+fn field_bool(value: &Value, key: &str, default: bool) -> Result<bool, String> {
+    match value.get(key) {
+        None | Some(Value::Null) => Ok(default),
+        Some(v) => v
+            .as_bool()
+            .ok_or_else(|| format!("\"{key}\" must be a boolean, got {v}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_full_settings() {
+        let settings = parse(
+            r#"{"wordsPerMinute": 450, "chunkSize": 3, "stripPunctuation": true, "gzipAutoDetect": false}"#,
+        )
+        .unwrap();
+        assert_eq!(settings.words_per_minute(), 450);
+        assert_eq!(settings.chunk_size(), 3);
+        assert!(settings.strip_punctuation());
+        assert!(!settings.gzip_auto_detect());
+    }
+
+    #[test]
+    fn defaults_missing_fields() {
+        let settings = parse("{}").unwrap();
+        assert_eq!(settings.words_per_minute(), 300);
+        assert_eq!(settings.chunk_size(), 1);
+        assert!(!settings.strip_punctuation());
+        assert!(settings.gzip_auto_detect());
+    }
+
+    #[test]
+    fn rejects_invalid_json() {
+        assert!(parse("not json").is_err());
+    }
+
+    #[test]
+    fn rejects_wrong_typed_field() {
+        let err = parse(r#"{"wordsPerMinute": "fast"}"#).unwrap_err();
+        assert!(err.contains("wordsPerMinute"));
+    }
+
+    #[test]
+    fn rejects_out_of_range_wpm() {
+        let err = parse(r#"{"wordsPerMinute": 9000}"#).unwrap_err();
+        assert!(err.contains("wordsPerMinute"));
+
+        assert!(parse(r#"{"wordsPerMinute": 1}"#).is_err());
+    }
+
+    #[test]
+    fn rejects_zero_chunk_size() {
+        let err = parse(r#"{"chunkSize": 0}"#).unwrap_err();
+        assert!(err.contains("chunkSize"));
+    }
+
+    #[test]
+    fn rejects_chunk_size_that_does_not_fit_target_usize() {
+        let err = parse(r#"{"chunkSize": 4294967297}"#).unwrap_err();
+        assert!(err.contains("chunkSize"));
+    }
+
+    #[test]
+    fn accepts_max_chunk_size() {
+        let settings = parse(r#"{"chunkSize": 4294967295}"#).unwrap();
+        assert_eq!(settings.chunk_size(), u32::MAX as usize);
+    }
+}